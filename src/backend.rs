@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use keyring_core::Store;
+
+/// Env var override for the backend selected by [`KeyringBackend::resolve`].
+///
+/// Takes precedence over the `backend` key in the plugin's Tauri config so a
+/// deployment can force a backend (e.g. in CI) without touching `tauri.conf.json`.
+pub const KEYRING_BACKEND_ENV: &str = "KEYRING_BACKEND";
+
+/// Which `keyring_core` store `init` should install.
+///
+/// Mirrors the pluggable credential-provider model Cargo uses for its
+/// `wincred` / `macos-keychain` / `gnome-secret` backends: the platform
+/// default is always available, and additional backends can be registered by
+/// name via [`register_store`] and selected by name here.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum KeyringBackend {
+    /// The platform-native store selected by `init`'s OS-specific branches.
+    #[default]
+    Platform,
+    /// A backend registered under this name via [`register_store`].
+    Named(String),
+}
+
+impl KeyringBackend {
+    /// Resolves the backend to install: the `KEYRING_BACKEND` env var, then
+    /// the config-supplied value, then the platform default.
+    pub(crate) fn resolve(configured: Option<&str>) -> KeyringBackend {
+        let chosen = std::env::var(KEYRING_BACKEND_ENV)
+            .ok()
+            .or_else(|| configured.map(str::to_string));
+
+        match chosen.as_deref() {
+            None | Some("platform") => KeyringBackend::Platform,
+            Some(name) => KeyringBackend::Named(name.to_string()),
+        }
+    }
+}
+
+type StoreFactory = Box<dyn Fn() -> crate::Result<Box<dyn Store>> + Send + Sync>;
+
+static REGISTRY: OnceLock<Mutex<HashMap<String, StoreFactory>>> = OnceLock::new();
+
+fn registry() -> &'static Mutex<HashMap<String, StoreFactory>> {
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Registers a custom `keyring_core` store under `name` so it can be
+/// selected at `init` time via the plugin config or `KEYRING_BACKEND`.
+///
+/// Call this before the plugin's `setup` hook runs, e.g. in `main` before
+/// building the app with `.plugin(tauri_plugin_keyring::init())`. The app's
+/// own `.setup()` closure runs after plugin setup hooks, which is too late.
+///
+/// This takes a factory rather than a single `Box<dyn Store>` because
+/// `keyring_core::set_default_store` only takes effect once `init` actually
+/// selects `name` as the active backend — registering a store doesn't
+/// install it. A factory also lets the same registration be reused if the
+/// plugin is ever re-initialized (e.g. in tests), without the registered
+/// store having already been moved into a previous `set_default_store` call.
+///
+/// Note the limitation this inherits from `keyring_core`: `set_default_store`
+/// is a process-global singleton, so only one registered backend can be
+/// active at a time — `register_store` lets an app *choose* which one by
+/// name, not run several side by side.
+pub fn register_store<F>(name: impl Into<String>, factory: F)
+where
+    F: Fn() -> crate::Result<Box<dyn Store>> + Send + Sync + 'static,
+{
+    registry()
+        .lock()
+        .expect("keyring backend registry poisoned")
+        .insert(name.into(), Box::new(factory));
+}
+
+/// Installs the store registered under `name` as the default store.
+///
+/// Returns `Ok(false)` if no store was registered under that name so the
+/// caller can fall back or report an error with more context.
+pub(crate) fn install_named_store(name: &str) -> crate::Result<bool> {
+    let guard = registry().lock().expect("keyring backend registry poisoned");
+    let Some(factory) = guard.get(name) else {
+        return Ok(false);
+    };
+    let store = factory()?;
+    drop(guard);
+
+    keyring_core::set_default_store(store);
+    Ok(true)
+}
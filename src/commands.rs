@@ -0,0 +1,58 @@
+//! `#[tauri::command]` wrappers so the frontend can reach the keyring
+//! without writing its own Rust commands.
+//!
+//! Each command drives the matching `*_async` method on `Keyring<R>`, so the
+//! blocking store call runs on `tauri::async_runtime`'s blocking pool rather
+//! than the command's executor thread. The "waiting" state the frontend
+//! needs is just the pending `Promise` `invoke()` already returns — no
+//! separate polling command is necessary on top of that.
+
+use tauri::{command, AppHandle, Runtime};
+
+use crate::{CredentialType, CredentialValue, KeyringExt};
+
+#[command]
+pub(crate) async fn set<R: Runtime>(
+    app: AppHandle<R>,
+    username: String,
+    credential_type: CredentialType,
+    value: CredentialValue,
+) -> crate::Result<()> {
+    app.keyring()
+        .set_async(username, credential_type, value)
+        .join()
+        .await
+}
+
+#[command]
+pub(crate) async fn get<R: Runtime>(
+    app: AppHandle<R>,
+    username: String,
+    credential_type: CredentialType,
+) -> crate::Result<CredentialValue> {
+    app.keyring().get_async(username, credential_type).join().await
+}
+
+#[command]
+pub(crate) async fn delete<R: Runtime>(
+    app: AppHandle<R>,
+    username: String,
+    credential_type: CredentialType,
+) -> crate::Result<()> {
+    app.keyring()
+        .delete_async(username, credential_type)
+        .join()
+        .await
+}
+
+#[command]
+pub(crate) async fn exists<R: Runtime>(
+    app: AppHandle<R>,
+    username: String,
+    credential_type: CredentialType,
+) -> crate::Result<bool> {
+    app.keyring()
+        .exists_async(username, credential_type)
+        .join()
+        .await
+}
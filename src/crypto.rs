@@ -0,0 +1,154 @@
+//! Passphrase-based envelope encryption for values that must not reach a
+//! backing store in plaintext.
+//!
+//! The sealed blob is self-describing — `version || log_n || salt || nonce
+//! || ciphertext`, base64-encoded — so the scrypt cost parameter can change
+//! across releases without breaking previously-stored entries.
+
+use base64::Engine;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::{KeyInit, XChaCha20Poly1305, XNonce};
+use scrypt::Params;
+
+const VERSION: u8 = 1;
+pub(crate) const DEFAULT_LOG_N: u8 = 16;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// Upper bound on the scrypt `log_n` cost parameter `open` will honor.
+///
+/// `log_n` for a decrypt comes from the sealed blob's header, which for the
+/// file-backed stores is attacker-controlled on-disk data — without a cap, a
+/// tampered blob could force an arbitrarily large scrypt memory allocation
+/// (roughly `128 * r * 2^log_n` bytes) as a denial-of-service. 20 is already
+/// far above [`DEFAULT_LOG_N`] and matches the ceiling most scrypt-backed KDF
+/// tools (e.g. `age`) treat as "intentionally expensive".
+const MAX_LOG_N: u8 = 20;
+
+fn derive_key(passphrase: &[u8], salt: &[u8], log_n: u8) -> crate::Result<[u8; KEY_LEN]> {
+    if log_n > MAX_LOG_N {
+        return Err(crate::Error::PlatformError(format!(
+            "scrypt log_n {log_n} exceeds the maximum of {MAX_LOG_N}"
+        )));
+    }
+    let params = Params::new(log_n, SCRYPT_R, SCRYPT_P, KEY_LEN)
+        .map_err(|e| crate::Error::PlatformError(format!("invalid scrypt params: {e}")))?;
+    let mut key = [0u8; KEY_LEN];
+    scrypt::scrypt(passphrase, salt, &params, &mut key)
+        .map_err(|e| crate::Error::PlatformError(format!("key derivation failed: {e}")))?;
+    Ok(key)
+}
+
+/// Seals `plaintext` under `passphrase`, using the given `salt`/`nonce`
+/// (caller-supplied so they can be generated with a CSPRNG at the call site)
+/// and [`DEFAULT_LOG_N`] as the scrypt cost parameter.
+pub(crate) fn seal(plaintext: &[u8], passphrase: &[u8], salt: &[u8; SALT_LEN], nonce: &[u8; NONCE_LEN]) -> crate::Result<String> {
+    seal_with_log_n(plaintext, passphrase, salt, nonce, DEFAULT_LOG_N)
+}
+
+pub(crate) fn seal_with_log_n(
+    plaintext: &[u8],
+    passphrase: &[u8],
+    salt: &[u8; SALT_LEN],
+    nonce: &[u8; NONCE_LEN],
+    log_n: u8,
+) -> crate::Result<String> {
+    let key = derive_key(passphrase, salt, log_n)?;
+    let cipher = XChaCha20Poly1305::new(&key.into());
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(nonce), plaintext)
+        .map_err(|e| crate::Error::PlatformError(format!("encryption failed: {e}")))?;
+
+    let mut blob = Vec::with_capacity(2 + SALT_LEN + NONCE_LEN + ciphertext.len());
+    blob.push(VERSION);
+    blob.push(log_n);
+    blob.extend_from_slice(salt);
+    blob.extend_from_slice(nonce);
+    blob.extend_from_slice(&ciphertext);
+    Ok(base64::engine::general_purpose::STANDARD.encode(blob))
+}
+
+/// Opens a blob produced by [`seal`]/[`seal_with_log_n`], re-deriving the key
+/// from the embedded salt and cost parameter.
+///
+/// Returns [`crate::Error::DecryptionFailed`] only when the AEAD tag check
+/// fails (a wrong passphrase, or a tampered salt/nonce/ciphertext), so
+/// callers can tell that apart from a malformed envelope (bad base64, too
+/// short, unknown version), which is reported as a different error.
+pub(crate) fn open(sealed: &str, passphrase: &[u8]) -> crate::Result<Vec<u8>> {
+    let blob = base64::engine::general_purpose::STANDARD
+        .decode(sealed)
+        .map_err(|e| crate::Error::PlatformError(format!("malformed sealed value: {e}")))?;
+
+    if blob.len() < 2 + SALT_LEN + NONCE_LEN {
+        return Err(crate::Error::PlatformError("sealed value too short".into()));
+    }
+    let (header, rest) = blob.split_at(2);
+    let (version, log_n) = (header[0], header[1]);
+    if version != VERSION {
+        return Err(crate::Error::PlatformError(format!(
+            "unsupported envelope version {version}"
+        )));
+    }
+    let (salt, rest) = rest.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let key = derive_key(passphrase, salt, log_n)?;
+    let cipher = XChaCha20Poly1305::new(&key.into());
+    cipher
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| crate::Error::DecryptionFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Low cost parameter so the test suite doesn't pay real scrypt cost.
+    const TEST_LOG_N: u8 = 4;
+
+    fn seal_for_test(plaintext: &[u8], passphrase: &[u8]) -> String {
+        let salt = [7u8; SALT_LEN];
+        let nonce = [9u8; NONCE_LEN];
+        seal_with_log_n(plaintext, passphrase, &salt, &nonce, TEST_LOG_N).unwrap()
+    }
+
+    #[test]
+    fn round_trips_through_seal_and_open() {
+        let sealed = seal_for_test(b"hunter2", b"correct horse");
+        assert_eq!(open(&sealed, b"correct horse").unwrap(), b"hunter2");
+    }
+
+    #[test]
+    fn wrong_passphrase_fails_with_decryption_failed() {
+        let sealed = seal_for_test(b"hunter2", b"correct horse");
+        assert!(matches!(open(&sealed, b"wrong horse"), Err(crate::Error::DecryptionFailed)));
+    }
+
+    #[test]
+    fn truncated_blob_is_rejected() {
+        let sealed = seal_for_test(b"hunter2", b"correct horse");
+        let mut blob = base64::engine::general_purpose::STANDARD.decode(sealed).unwrap();
+        blob.truncate(2 + SALT_LEN);
+        let truncated = base64::engine::general_purpose::STANDARD.encode(blob);
+        assert!(open(&truncated, b"correct horse").is_err());
+    }
+
+    #[test]
+    fn unsupported_version_is_rejected() {
+        let sealed = seal_for_test(b"hunter2", b"correct horse");
+        let mut blob = base64::engine::general_purpose::STANDARD.decode(sealed).unwrap();
+        blob[0] = VERSION + 1;
+        let bumped = base64::engine::general_purpose::STANDARD.encode(blob);
+        assert!(open(&bumped, b"correct horse").is_err());
+    }
+
+    #[test]
+    fn log_n_above_the_maximum_is_rejected() {
+        let salt = [7u8; SALT_LEN];
+        assert!(derive_key(b"correct horse", &salt, MAX_LOG_N + 1).is_err());
+    }
+}
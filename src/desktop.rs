@@ -1,21 +1,45 @@
-use serde::de::DeserializeOwned;
-use tauri::{plugin::PluginApi, AppHandle, Runtime};
+use tauri::{plugin::PluginApi, AppHandle, Manager, Runtime};
 
+use crate::backend::{self, KeyringBackend};
 use crate::implementation::KeyringImplementation;
 use crate::models::*;
 
-pub fn init<R: Runtime, C: DeserializeOwned>(
+pub fn init<R: Runtime>(
     app: &AppHandle<R>,
-    _api: PluginApi<R, C>,
+    api: PluginApi<R, Config>,
 ) -> crate::Result<Keyring<R>> {
+    let backend = KeyringBackend::resolve(api.config().backend.as_deref());
+
     if std::env::var("KEYRING_USE_MOCK").is_ok() {
         use keyring_core::mock::Store;
         let store = Store::new().map_err(|e| crate::Error::PlatformError(e.to_string()))?;
         keyring_core::set_default_store(store);
-        return Ok(Keyring(app.clone()));
+        return Ok(Keyring(app.clone(), backend));
+    }
+
+    if let KeyringBackend::Named(name) = &backend {
+        if !backend::install_named_store(name)? {
+            return Err(crate::Error::PlatformError(format!(
+                "no store registered for backend \"{name}\""
+            )));
+        }
+        return Ok(Keyring(app.clone(), backend));
     }
 
-    // Initialize platform-specific store
+    if let Err(e) = install_platform_store(app) {
+        if file_store_fallback_enabled(&api) {
+            install_file_store_fallback(app)?;
+        } else {
+            return Err(e);
+        }
+    }
+
+    Ok(Keyring(app.clone(), backend))
+}
+
+/// Installs the platform-native `keyring_core` store for the current OS.
+#[allow(unused_variables)]
+fn install_platform_store<R: Runtime>(app: &AppHandle<R>) -> crate::Result<()> {
     #[cfg(target_os = "windows")]
     {
         use windows_native_keyring_store::Store as WindowsStore;
@@ -32,25 +56,71 @@ pub fn init<R: Runtime, C: DeserializeOwned>(
 
     #[cfg(target_os = "linux")]
     {
-        #[cfg(feature = "dbus-secret-service")]
-        use dbus_secret_service_keyring_store::Store as LinuxStore;
-        #[cfg(feature = "linux-keyutils")]
-        use linux_keyutils_keyring_store::Store as LinuxStore;
-        let store = LinuxStore::new().map_err(|e| crate::Error::PlatformError(e.to_string()))?;
-        keyring_core::set_default_store(store);
+        use crate::stores::linux_portal::{is_sandboxed, PortalStore};
+
+        if is_sandboxed() {
+            // Inside a Flatpak/Snap sandbox, direct `org.freedesktop.Secret`
+            // access is unavailable; go through the portal instead. `oo7`
+            // owns its own on-disk storage for portal-backed items, so
+            // there's no separate vault file to create here.
+            let store = PortalStore::new()?;
+            keyring_core::set_default_store(store);
+        } else {
+            #[cfg(feature = "dbus-secret-service")]
+            use dbus_secret_service_keyring_store::Store as LinuxStore;
+            #[cfg(feature = "linux-keyutils")]
+            use linux_keyutils_keyring_store::Store as LinuxStore;
+            let store = LinuxStore::new().map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+            keyring_core::set_default_store(store);
+        }
     }
 
-    Ok(Keyring(app.clone()))
+    Ok(())
+}
+
+/// Whether the encrypted-file fallback may be used when the platform store
+/// fails to initialize. Opt-in only (config flag or `KEYRING_FILE_STORE_FALLBACK`)
+/// so an app is never silently downgraded to a weaker store.
+fn file_store_fallback_enabled<R: Runtime>(api: &PluginApi<R, Config>) -> bool {
+    std::env::var("KEYRING_FILE_STORE_FALLBACK").is_ok() || api.config().file_store_fallback
+}
+
+/// Installs [`crate::stores::encrypted_file::EncryptedFileStore`] as the
+/// default store, rooted in the app's data directory.
+fn install_file_store_fallback<R: Runtime>(app: &AppHandle<R>) -> crate::Result<()> {
+    use crate::stores::encrypted_file::EncryptedFileStore;
+
+    let passphrase = std::env::var("KEYRING_FILE_STORE_PASSPHRASE").map_err(|_| {
+        crate::Error::PlatformError(
+            "KEYRING_FILE_STORE_PASSPHRASE must be set to use the encrypted file store fallback"
+                .into(),
+        )
+    })?;
+
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+    std::fs::create_dir_all(&data_dir).map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+
+    let store = EncryptedFileStore::new(data_dir.join("keyring.json"), passphrase)?;
+    keyring_core::set_default_store(store);
+    Ok(())
 }
 
 /// Access to the keyring APIs.
-pub struct Keyring<R: Runtime>(AppHandle<R>);
+pub struct Keyring<R: Runtime>(AppHandle<R>, KeyringBackend);
 
 impl<R: Runtime> Keyring<R> {
     fn implementation(&self) -> KeyringImplementation {
         KeyringImplementation
     }
 
+    /// The storage backend this instance was initialized with.
+    pub fn backend(&self) -> &KeyringBackend {
+        &self.1
+    }
+
     pub fn initialize_service(&self, service_name: String) -> crate::Result<()> {
         KeyringImplementation::initialize_service(service_name)
     }
@@ -79,4 +149,102 @@ impl<R: Runtime> Keyring<R> {
     pub fn exists(&self, username: &str, credential_type: CredentialType) -> crate::Result<bool> {
         self.implementation().exists(username, credential_type)
     }
+
+    /// Like [`Self::set`], but encrypts `value` with `passphrase` before it
+    /// reaches the store.
+    pub fn set_encrypted(
+        &self,
+        username: &str,
+        credential_type: CredentialType,
+        value: CredentialValue,
+        passphrase: &str,
+    ) -> crate::Result<()> {
+        self.implementation()
+            .set_encrypted(username, credential_type, value, passphrase)
+    }
+
+    /// Like [`Self::get`], but decrypts the stored value with `passphrase`.
+    pub fn get_encrypted(
+        &self,
+        username: &str,
+        credential_type: CredentialType,
+        passphrase: &str,
+    ) -> crate::Result<CredentialValue> {
+        self.implementation()
+            .get_encrypted(username, credential_type, passphrase)
+    }
+
+    /// Like [`Self::set`], but runs on `tauri::async_runtime`'s blocking pool
+    /// so a slow store (e.g. a D-Bus unlock prompt) doesn't stall the
+    /// caller's thread.
+    pub fn set_async(
+        &self,
+        username: String,
+        credential_type: CredentialType,
+        value: CredentialValue,
+    ) -> crate::KeyringTask<()> {
+        let implementation = self.implementation();
+        crate::KeyringTask::spawn(move || implementation.set(&username, credential_type, value))
+    }
+
+    /// Like [`Self::get`], but runs on `tauri::async_runtime`'s blocking pool.
+    pub fn get_async(&self, username: String, credential_type: CredentialType) -> crate::KeyringTask<CredentialValue> {
+        let implementation = self.implementation();
+        crate::KeyringTask::spawn(move || implementation.get(&username, credential_type))
+    }
+
+    /// Like [`Self::delete`], but runs on `tauri::async_runtime`'s blocking pool.
+    pub fn delete_async(&self, username: String, credential_type: CredentialType) -> crate::KeyringTask<()> {
+        let implementation = self.implementation();
+        crate::KeyringTask::spawn(move || implementation.delete(&username, credential_type))
+    }
+
+    /// Like [`Self::exists`], but runs on `tauri::async_runtime`'s blocking pool.
+    pub fn exists_async(&self, username: String, credential_type: CredentialType) -> crate::KeyringTask<bool> {
+        let implementation = self.implementation();
+        crate::KeyringTask::spawn(move || implementation.exists(&username, credential_type))
+    }
+
+    /// Generates a non-extractable hardware-backed key for `username` and
+    /// returns its public key. On macOS this is a Secure Enclave P-256 key;
+    /// other desktop platforms return [`crate::Error::Unsupported`].
+    pub fn generate_key(&self, username: &str, key_spec: KeySpec) -> crate::Result<PublicKey> {
+        #[cfg(target_os = "macos")]
+        {
+            crate::hardware::generate_key(username, &key_spec)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (username, key_spec);
+            Err(crate::Error::Unsupported)
+        }
+    }
+
+    /// Signs `data` with the hardware-backed key previously created for
+    /// `username` via [`Self::generate_key`].
+    pub fn sign(&self, username: &str, data: &[u8]) -> crate::Result<Signature> {
+        #[cfg(target_os = "macos")]
+        {
+            crate::hardware::sign(username, data)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (username, data);
+            Err(crate::Error::Unsupported)
+        }
+    }
+
+    /// Returns the public key for the hardware-backed key previously
+    /// created for `username` via [`Self::generate_key`].
+    pub fn public_key(&self, username: &str) -> crate::Result<PublicKey> {
+        #[cfg(target_os = "macos")]
+        {
+            crate::hardware::public_key(username)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = username;
+            Err(crate::Error::Unsupported)
+        }
+    }
 }
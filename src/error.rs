@@ -0,0 +1,34 @@
+use serde::{Serialize, Serializer};
+
+/// Errors that can occur while interacting with the platform keyring.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Tauri(#[from] tauri::Error),
+    #[cfg(mobile)]
+    #[error(transparent)]
+    PluginInvoke(#[from] tauri::plugin::mobile::PluginInvokeError),
+    /// The underlying `keyring_core` store returned an error, or setup,
+    /// key derivation or envelope parsing failed; carries the message.
+    #[error("platform keyring error: {0}")]
+    PlatformError(String),
+    /// No credential was found for the given username/type.
+    #[error("credential not found")]
+    NotFound,
+    /// An encrypted value failed to decrypt — almost always a wrong passphrase.
+    #[error("decryption failed: wrong passphrase or corrupted data")]
+    DecryptionFailed,
+    /// The requested operation has no hardware-backed implementation on this
+    /// platform (e.g. hardware keys off Apple/Android).
+    #[error("operation not supported on this platform")]
+    Unsupported,
+}
+
+// `tauri::command` handlers need errors to serialize to the frontend.
+impl Serialize for Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
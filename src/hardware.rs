@@ -0,0 +1,92 @@
+//! Hardware-backed (non-extractable) key generation and signing.
+//!
+//! Unlike `set`/`get`, the private key here never crosses into Rust-owned
+//! memory — only a public key and signatures do. On Apple platforms this is
+//! backed directly by the Secure Enclave via `security-framework`; Android
+//! routes through the registered Kotlin plugin since AndroidKeyStore/StrongBox
+//! access isn't reachable from plain Rust (see `mobile.rs`).
+
+use crate::models::{KeySpec, PublicKey, Signature};
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+pub(crate) fn generate_key(username: &str, spec: &KeySpec) -> crate::Result<PublicKey> {
+    use security_framework::key::{GenerateKeyOptions, KeyType, Token};
+
+    let KeySpec::P256 = spec;
+
+    let mut options = GenerateKeyOptions::default();
+    options.set_key_type(KeyType::ec());
+    options.set_key_size(256);
+    options.set_token(Token::SecureEnclave);
+    options.set_label(username);
+
+    let key = options
+        .generate()
+        .map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+    public_key_of(&key)
+}
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+pub(crate) fn sign(username: &str, data: &[u8]) -> crate::Result<Signature> {
+    use security_framework::key::Algorithm;
+
+    let key = find_by_label(username)?;
+    let signature = key
+        .create_signature(Algorithm::ECDSASignatureMessageX962SHA256, data)
+        .map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+    Ok(Signature(signature))
+}
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+pub(crate) fn public_key(username: &str) -> crate::Result<PublicKey> {
+    let key = find_by_label(username)?;
+    public_key_of(&key)
+}
+
+/// Looks up a previously-generated key by the label `generate_key` gave it,
+/// via a keychain item search rather than a (nonexistent) `SecKey` lookup.
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+fn find_by_label(username: &str) -> crate::Result<security_framework::key::SecKey> {
+    use security_framework::item::{ItemClass, ItemSearchOptions, Reference, SearchResult};
+
+    let results = ItemSearchOptions::new()
+        .class(ItemClass::key())
+        .label(username)
+        .load_refs(true)
+        .search()
+        .map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+
+    results
+        .into_iter()
+        .find_map(|item| match item {
+            SearchResult::Ref(Reference::Key(key)) => Some(key),
+            _ => None,
+        })
+        .ok_or(crate::Error::NotFound)
+}
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+fn public_key_of(key: &security_framework::key::SecKey) -> crate::Result<PublicKey> {
+    let public = key
+        .public_key()
+        .ok_or_else(|| crate::Error::PlatformError("key has no public half".into()))?;
+    let representation = public
+        .external_representation()
+        .ok_or_else(|| crate::Error::PlatformError("no external key representation".into()))?;
+    Ok(PublicKey(representation.to_vec()))
+}
+
+#[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "android")))]
+pub(crate) fn generate_key(_username: &str, _spec: &KeySpec) -> crate::Result<PublicKey> {
+    Err(crate::Error::Unsupported)
+}
+
+#[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "android")))]
+pub(crate) fn sign(_username: &str, _data: &[u8]) -> crate::Result<Signature> {
+    Err(crate::Error::Unsupported)
+}
+
+#[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "android")))]
+pub(crate) fn public_key(_username: &str) -> crate::Result<PublicKey> {
+    Err(crate::Error::Unsupported)
+}
@@ -0,0 +1,208 @@
+use std::sync::OnceLock;
+
+use keyring_core::Entry;
+use scrypt::password_hash::rand_core::{OsRng, RngCore};
+
+use crate::crypto::{self, DEFAULT_LOG_N};
+use crate::models::*;
+
+static SERVICE_NAME: OnceLock<String> = OnceLock::new();
+
+const DEFAULT_SERVICE: &str = "tauri-plugin-keyring";
+
+/// Bridges `Keyring<R>`'s public API to the active `keyring_core` store.
+pub struct KeyringImplementation;
+
+impl KeyringImplementation {
+    /// Sets the service name entries are stored under. Must be called at
+    /// most once; subsequent calls are a no-op error.
+    pub fn initialize_service(service_name: String) -> crate::Result<()> {
+        SERVICE_NAME
+            .set(service_name)
+            .map_err(|_| crate::Error::PlatformError("service already initialized".into()))
+    }
+
+    fn service() -> &'static str {
+        SERVICE_NAME.get().map(String::as_str).unwrap_or(DEFAULT_SERVICE)
+    }
+
+    fn entry(username: &str, credential_type: CredentialType) -> crate::Result<Entry> {
+        Entry::new(&format!("{}:{}", Self::service(), credential_type.as_str()), username)
+            .map_err(|e| crate::Error::PlatformError(e.to_string()))
+    }
+
+    pub fn set(
+        &self,
+        username: &str,
+        credential_type: CredentialType,
+        value: CredentialValue,
+    ) -> crate::Result<()> {
+        let entry = Self::entry(username, credential_type)?;
+        let bytes = match value {
+            CredentialValue::Text(s) => s.into_bytes(),
+            CredentialValue::Binary(b) => b,
+        };
+        entry
+            .set_secret(&bytes)
+            .map_err(|e| crate::Error::PlatformError(e.to_string()))
+    }
+
+    // A store only ever holds one secret blob per entry — `get_password`
+    // is just `get_secret` with a UTF-8 decode on top — so round-tripping
+    // `CredentialValue::Binary` through `get_password` loses non-UTF-8 bytes.
+    // Read the raw bytes back and only present them as `Text` when they
+    // actually decode, rather than trusting how the caller originally framed
+    // the value.
+    pub fn get(&self, username: &str, credential_type: CredentialType) -> crate::Result<CredentialValue> {
+        let entry = Self::entry(username, credential_type)?;
+        let bytes = entry
+            .get_secret()
+            .map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+        match String::from_utf8(bytes) {
+            Ok(text) => Ok(CredentialValue::Text(text)),
+            Err(e) => Ok(CredentialValue::Binary(e.into_bytes())),
+        }
+    }
+
+    pub fn delete(&self, username: &str, credential_type: CredentialType) -> crate::Result<()> {
+        let entry = Self::entry(username, credential_type)?;
+        entry
+            .delete_credential()
+            .map_err(|e| crate::Error::PlatformError(e.to_string()))
+    }
+
+    pub fn exists(&self, username: &str, credential_type: CredentialType) -> crate::Result<bool> {
+        let entry = Self::entry(username, credential_type)?;
+        entry.exists().map_err(|e| crate::Error::PlatformError(e.to_string()))
+    }
+
+    /// Like [`Self::set`], but seals `value` with `passphrase` (scrypt +
+    /// XChaCha20-Poly1305, see [`crate::crypto`]) before it reaches the store,
+    /// using [`DEFAULT_LOG_N`] as the scrypt cost parameter.
+    pub fn set_encrypted(
+        &self,
+        username: &str,
+        credential_type: CredentialType,
+        value: CredentialValue,
+        passphrase: &str,
+    ) -> crate::Result<()> {
+        self.set_encrypted_with_log_n(username, credential_type, value, passphrase, DEFAULT_LOG_N)
+    }
+
+    /// Like [`Self::set_encrypted`], but with an explicit scrypt `log_n` cost
+    /// parameter for callers that need to tune key-derivation cost.
+    pub fn set_encrypted_with_log_n(
+        &self,
+        username: &str,
+        credential_type: CredentialType,
+        value: CredentialValue,
+        passphrase: &str,
+        log_n: u8,
+    ) -> crate::Result<()> {
+        // Prefix a variant tag before sealing so `get_encrypted` can hand
+        // back the same `Text`/`Binary` shape the caller originally sealed,
+        // instead of always returning raw bytes.
+        let (tag, plaintext) = match value {
+            CredentialValue::Text(s) => (VARIANT_TEXT, s.into_bytes()),
+            CredentialValue::Binary(b) => (VARIANT_BINARY, b),
+        };
+        let mut framed = Vec::with_capacity(1 + plaintext.len());
+        framed.push(tag);
+        framed.extend_from_slice(&plaintext);
+
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 24];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+
+        let sealed = crypto::seal_with_log_n(&framed, passphrase.as_bytes(), &salt, &nonce, log_n)?;
+        self.set(username, credential_type, CredentialValue::Text(sealed))
+    }
+
+    /// Like [`Self::get`], but unseals the stored value with `passphrase`,
+    /// returning it as the same `Text`/`Binary` variant it was sealed with.
+    /// Fails with [`crate::Error::DecryptionFailed`] if `passphrase` is wrong.
+    pub fn get_encrypted(
+        &self,
+        username: &str,
+        credential_type: CredentialType,
+        passphrase: &str,
+    ) -> crate::Result<CredentialValue> {
+        let sealed = match self.get(username, credential_type)? {
+            CredentialValue::Text(s) => s,
+            CredentialValue::Binary(_) => {
+                return Err(crate::Error::PlatformError(
+                    "stored value is not a sealed envelope".into(),
+                ))
+            }
+        };
+
+        let framed = crypto::open(&sealed, passphrase.as_bytes())?;
+        let (&tag, plaintext) = framed
+            .split_first()
+            .ok_or_else(|| crate::Error::PlatformError("sealed value is empty".into()))?;
+
+        match tag {
+            VARIANT_TEXT => String::from_utf8(plaintext.to_vec())
+                .map(CredentialValue::Text)
+                .map_err(|_| crate::Error::PlatformError("sealed text is not valid UTF-8".into())),
+            VARIANT_BINARY => Ok(CredentialValue::Binary(plaintext.to_vec())),
+            other => Err(crate::Error::PlatformError(format!("unknown sealed value tag {other}"))),
+        }
+    }
+}
+
+const VARIANT_TEXT: u8 = 0;
+const VARIANT_BINARY: u8 = 1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `frame`/`unframe` restate the variant-tag framing that
+    // `set_encrypted_with_log_n`/`get_encrypted` inline, so these tests check
+    // that framing scheme without a live `keyring_core::Entry` (no store is
+    // installed in this test binary). They do not call those methods or
+    // `crate::crypto::seal`/`open`.
+    fn frame(value: CredentialValue) -> (u8, Vec<u8>) {
+        match value {
+            CredentialValue::Text(s) => (VARIANT_TEXT, s.into_bytes()),
+            CredentialValue::Binary(b) => (VARIANT_BINARY, b),
+        }
+    }
+
+    fn unframe(tag: u8, plaintext: Vec<u8>) -> crate::Result<CredentialValue> {
+        match tag {
+            VARIANT_TEXT => String::from_utf8(plaintext)
+                .map(CredentialValue::Text)
+                .map_err(|_| crate::Error::PlatformError("sealed text is not valid UTF-8".into())),
+            VARIANT_BINARY => Ok(CredentialValue::Binary(plaintext)),
+            other => Err(crate::Error::PlatformError(format!("unknown sealed value tag {other}"))),
+        }
+    }
+
+    #[test]
+    fn text_variant_round_trips() {
+        let (tag, plaintext) = frame(CredentialValue::Text("hello".into()));
+        assert_eq!(tag, VARIANT_TEXT);
+        match unframe(tag, plaintext).unwrap() {
+            CredentialValue::Text(s) => assert_eq!(s, "hello"),
+            CredentialValue::Binary(_) => panic!("expected Text"),
+        }
+    }
+
+    #[test]
+    fn binary_variant_round_trips() {
+        let (tag, plaintext) = frame(CredentialValue::Binary(vec![0xff, 0x00, 0x10]));
+        assert_eq!(tag, VARIANT_BINARY);
+        match unframe(tag, plaintext).unwrap() {
+            CredentialValue::Binary(b) => assert_eq!(b, vec![0xff, 0x00, 0x10]),
+            CredentialValue::Text(_) => panic!("expected Binary"),
+        }
+    }
+
+    #[test]
+    fn unknown_tag_is_rejected() {
+        assert!(unframe(2, vec![1, 2, 3]).is_err());
+    }
+}
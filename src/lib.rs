@@ -0,0 +1,61 @@
+use tauri::{
+    plugin::{Builder, TauriPlugin},
+    Manager, Runtime,
+};
+
+pub use backend::{register_store, KeyringBackend};
+pub use error::{Error, Result};
+pub use models::*;
+
+mod backend;
+mod commands;
+mod crypto;
+mod error;
+mod hardware;
+mod implementation;
+mod models;
+mod stores;
+mod task;
+
+pub use task::KeyringTask;
+
+#[cfg(desktop)]
+mod desktop;
+#[cfg(mobile)]
+mod mobile;
+
+#[cfg(desktop)]
+use desktop::Keyring;
+#[cfg(mobile)]
+use mobile::Keyring;
+
+/// Extension trait giving any Tauri `Manager` access to the keyring plugin state.
+pub trait KeyringExt<R: Runtime> {
+    fn keyring(&self) -> &Keyring<R>;
+}
+
+impl<R: Runtime, T: Manager<R>> KeyringExt<R> for T {
+    fn keyring(&self) -> &Keyring<R> {
+        self.state::<Keyring<R>>().inner()
+    }
+}
+
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    Builder::<R, Config>::new("keyring")
+        .invoke_handler(tauri::generate_handler![
+            commands::set,
+            commands::get,
+            commands::delete,
+            commands::exists,
+        ])
+        .setup(|app, api| {
+            #[cfg(desktop)]
+            let keyring = desktop::init(app, api)?;
+            #[cfg(mobile)]
+            let keyring = mobile::init(app, api)?;
+
+            app.manage(keyring);
+            Ok(())
+        })
+        .build()
+}
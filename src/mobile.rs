@@ -1,9 +1,9 @@
-use serde::de::DeserializeOwned;
 use tauri::{
     plugin::{PluginApi, PluginHandle},
     AppHandle, Runtime,
 };
 
+use crate::backend::{self, KeyringBackend};
 use crate::implementation::KeyringImplementation;
 use crate::models::*;
 
@@ -11,19 +11,47 @@ use crate::models::*;
 tauri::ios_plugin_binding!(init_plugin_keyring);
 
 // initializes the Kotlin or Swift plugin classes
-pub fn init<R: Runtime, C: DeserializeOwned>(
+pub fn init<R: Runtime>(
     _app: &AppHandle<R>,
-    api: PluginApi<R, C>,
+    api: PluginApi<R, Config>,
 ) -> crate::Result<Keyring<R>> {
+    let backend = KeyringBackend::resolve(api.config().backend.as_deref());
+
+    if let KeyringBackend::Named(name) = &backend {
+        if !backend::install_named_store(name)? {
+            return Err(crate::Error::PlatformError(format!(
+                "no store registered for backend \"{name}\""
+            )));
+        }
+    } else {
+        install_platform_store()?;
+    }
+
+    #[cfg(target_os = "android")]
+    {
+        let handle =
+            api.register_android_plugin("com.alaydriem.bvc.plugin.keyring", "KeyringPlugin")?;
+        Ok(Keyring(handle, backend))
+    }
+
+    #[cfg(target_os = "ios")]
+    {
+        let handle = api.register_ios_plugin(init_plugin_keyring)?;
+        Ok(Keyring(handle, backend))
+    }
+}
+
+/// Installs the platform-native `keyring_core` store, mirroring
+/// `desktop::install_platform_store` for the mobile targets. Only run when
+/// [`KeyringBackend`] resolved to [`KeyringBackend::Platform`]; a
+/// [`KeyringBackend::Named`] backend is installed by the caller instead.
+fn install_platform_store() -> crate::Result<()> {
     #[cfg(target_os = "android")]
     {
         use android_native_keyring_store::AndroidStore;
         let store = AndroidStore::from_ndk_context()
             .map_err(|e| crate::Error::PlatformError(e.to_string()))?;
         keyring_core::set_default_store(store);
-        let handle =
-            api.register_android_plugin("com.alaydriem.bvc.plugin.keyring", "KeyringPlugin")?;
-        Ok(Keyring(handle))
     }
 
     #[cfg(target_os = "ios")]
@@ -31,19 +59,24 @@ pub fn init<R: Runtime, C: DeserializeOwned>(
         use apple_native_keyring_store::protected::Store as IOSStore;
         let store = IOSStore::new().map_err(|e| crate::Error::PlatformError(e.to_string()))?;
         keyring_core::set_default_store(store);
-        let handle = api.register_ios_plugin(init_plugin_keyring)?;
-        Ok(Keyring(handle))
     }
+
+    Ok(())
 }
 
 /// Access to the keyring APIs.
-pub struct Keyring<R: Runtime>(PluginHandle<R>);
+pub struct Keyring<R: Runtime>(PluginHandle<R>, KeyringBackend);
 
 impl<R: Runtime> Keyring<R> {
     fn implementation(&self) -> KeyringImplementation {
         KeyringImplementation
     }
 
+    /// The storage backend this instance was initialized with.
+    pub fn backend(&self) -> &KeyringBackend {
+        &self.1
+    }
+
     pub fn initialize_service(&self, service_name: String) -> crate::Result<()> {
         KeyringImplementation::initialize_service(service_name)
     }
@@ -72,4 +105,126 @@ impl<R: Runtime> Keyring<R> {
     pub fn exists(&self, username: &str, credential_type: CredentialType) -> crate::Result<bool> {
         self.implementation().exists(username, credential_type)
     }
+
+    /// Like [`Self::set`], but encrypts `value` with `passphrase` before it
+    /// reaches the store.
+    pub fn set_encrypted(
+        &self,
+        username: &str,
+        credential_type: CredentialType,
+        value: CredentialValue,
+        passphrase: &str,
+    ) -> crate::Result<()> {
+        self.implementation()
+            .set_encrypted(username, credential_type, value, passphrase)
+    }
+
+    /// Like [`Self::get`], but decrypts the stored value with `passphrase`.
+    pub fn get_encrypted(
+        &self,
+        username: &str,
+        credential_type: CredentialType,
+        passphrase: &str,
+    ) -> crate::Result<CredentialValue> {
+        self.implementation()
+            .get_encrypted(username, credential_type, passphrase)
+    }
+
+    /// Like [`Self::set`], but runs on `tauri::async_runtime`'s blocking pool
+    /// so a slow store doesn't stall the caller's thread.
+    pub fn set_async(
+        &self,
+        username: String,
+        credential_type: CredentialType,
+        value: CredentialValue,
+    ) -> crate::KeyringTask<()> {
+        let implementation = self.implementation();
+        crate::KeyringTask::spawn(move || implementation.set(&username, credential_type, value))
+    }
+
+    /// Like [`Self::get`], but runs on `tauri::async_runtime`'s blocking pool.
+    pub fn get_async(&self, username: String, credential_type: CredentialType) -> crate::KeyringTask<CredentialValue> {
+        let implementation = self.implementation();
+        crate::KeyringTask::spawn(move || implementation.get(&username, credential_type))
+    }
+
+    /// Like [`Self::delete`], but runs on `tauri::async_runtime`'s blocking pool.
+    pub fn delete_async(&self, username: String, credential_type: CredentialType) -> crate::KeyringTask<()> {
+        let implementation = self.implementation();
+        crate::KeyringTask::spawn(move || implementation.delete(&username, credential_type))
+    }
+
+    /// Like [`Self::exists`], but runs on `tauri::async_runtime`'s blocking pool.
+    pub fn exists_async(&self, username: String, credential_type: CredentialType) -> crate::KeyringTask<bool> {
+        let implementation = self.implementation();
+        crate::KeyringTask::spawn(move || implementation.exists(&username, credential_type))
+    }
+
+    /// Generates a non-extractable hardware-backed key for `username` and
+    /// returns its public key. On iOS this is a Secure Enclave P-256 key; on
+    /// Android it's an AndroidKeyStore key generated with StrongBox
+    /// requested, created via the registered Kotlin plugin.
+    pub fn generate_key(&self, username: &str, key_spec: KeySpec) -> crate::Result<PublicKey> {
+        #[cfg(target_os = "ios")]
+        {
+            crate::hardware::generate_key(username, &key_spec)
+        }
+        #[cfg(target_os = "android")]
+        {
+            self.0
+                .run_mobile_plugin("generateHardwareKey", HardwareKeyRequest { username, key_spec })
+                .map_err(crate::Error::from)
+        }
+    }
+
+    /// Signs `data` with the hardware-backed key previously created for
+    /// `username` via [`Self::generate_key`]. The private key never leaves
+    /// the Secure Enclave/AndroidKeyStore.
+    pub fn sign(&self, username: &str, data: &[u8]) -> crate::Result<Signature> {
+        #[cfg(target_os = "ios")]
+        {
+            crate::hardware::sign(username, data)
+        }
+        #[cfg(target_os = "android")]
+        {
+            self.0
+                .run_mobile_plugin("sign", HardwareSignRequest { username, data })
+                .map_err(crate::Error::from)
+        }
+    }
+
+    /// Returns the public key for the hardware-backed key previously
+    /// created for `username` via [`Self::generate_key`].
+    pub fn public_key(&self, username: &str) -> crate::Result<PublicKey> {
+        #[cfg(target_os = "ios")]
+        {
+            crate::hardware::public_key(username)
+        }
+        #[cfg(target_os = "android")]
+        {
+            self.0
+                .run_mobile_plugin("publicKey", HardwarePublicKeyRequest { username })
+                .map_err(crate::Error::from)
+        }
+    }
+}
+
+#[cfg(target_os = "android")]
+#[derive(serde::Serialize)]
+struct HardwareKeyRequest<'a> {
+    username: &'a str,
+    key_spec: KeySpec,
+}
+
+#[cfg(target_os = "android")]
+#[derive(serde::Serialize)]
+struct HardwareSignRequest<'a> {
+    username: &'a str,
+    data: &'a [u8],
+}
+
+#[cfg(target_os = "android")]
+#[derive(serde::Serialize)]
+struct HardwarePublicKeyRequest<'a> {
+    username: &'a str,
 }
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of credential stored for a given username.
+///
+/// `keyring_core` stores are keyed by `(service, username)`; the credential
+/// type is folded into the service name so multiple credential kinds can
+/// coexist for the same username without colliding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialType {
+    Password,
+    Token,
+    Certificate,
+    /// Names non-extractable hardware-backed keys (Secure Enclave /
+    /// StrongBox), which are managed through `generate_key`/`sign`/
+    /// `public_key`; those methods are keyed by username alone. `set`/`get`
+    /// do not reject this type: they store and read an ordinary value under
+    /// it, separate from any hardware key.
+    HardwareKey,
+}
+
+impl CredentialType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            CredentialType::Password => "password",
+            CredentialType::Token => "token",
+            CredentialType::Certificate => "certificate",
+            CredentialType::HardwareKey => "hardware_key",
+        }
+    }
+}
+
+/// Parameters for hardware-backed key generation via `generate_key`.
+///
+/// Only P-256 is supported today, since that's the common denominator
+/// between Secure Enclave and StrongBox-backed AndroidKeyStore keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeySpec {
+    P256,
+}
+
+/// A SEC1 uncompressed public key point for a hardware-backed key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicKey(pub Vec<u8>);
+
+/// An ECDSA-SHA256 signature produced by a hardware-backed key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Signature(pub Vec<u8>);
+
+/// A credential value as it crosses the Tauri IPC boundary.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CredentialValue {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Plugin configuration read from the `plugins.keyring` key of `tauri.conf.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    /// Name of the storage backend to install. `"platform"` (the default)
+    /// uses the OS-native store; any other value must have been registered
+    /// via `register_store`. Overridden by the `KEYRING_BACKEND` env var.
+    #[serde(default)]
+    pub backend: Option<String>,
+
+    /// Whether `init` may fall back to an encrypted on-disk store when the
+    /// platform store is unavailable (no D-Bus, no keyutils, sandboxed). Off
+    /// by default so a broken platform store fails loudly instead of
+    /// silently degrading. Also settable via `KEYRING_FILE_STORE_FALLBACK`.
+    #[serde(default)]
+    pub file_store_fallback: bool,
+}
@@ -0,0 +1,97 @@
+//! A `keyring_core` store that persists entries to an encrypted JSON file.
+//!
+//! Intended as an explicit opt-in fallback for environments where no
+//! platform secret store is reachable (headless CI, containers, sandboxes
+//! without D-Bus/keyutils) — see `init`'s `KEYRING_FILE_STORE_FALLBACK`
+//! handling in `desktop.rs`.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use keyring_core::{CredentialApi, Error as StoreError, Store};
+
+use super::vault::{self, JsonVault};
+
+/// An encrypted, file-backed `keyring_core::Store`.
+///
+/// Every value is sealed with a passphrase-derived key (see
+/// [`crate::crypto`]) before it touches disk; the file itself only ever
+/// holds base64 ciphertext, never plaintext secrets.
+pub struct EncryptedFileStore {
+    vault: Arc<JsonVault>,
+    passphrase: Arc<str>,
+}
+
+impl EncryptedFileStore {
+    /// Opens (or lazily creates) the encrypted file store at `path`,
+    /// sealing/opening entries with `passphrase`.
+    pub fn new(path: PathBuf, passphrase: impl Into<String>) -> crate::Result<Self> {
+        Ok(Self {
+            vault: Arc::new(JsonVault::new(path)),
+            passphrase: Arc::from(passphrase.into()),
+        })
+    }
+}
+
+impl Store for EncryptedFileStore {
+    fn id(&self) -> String {
+        "encrypted-file".to_string()
+    }
+
+    fn build(
+        &self,
+        _target: Option<&str>,
+        service: &str,
+        user: &str,
+    ) -> Result<Arc<dyn CredentialApi>, StoreError> {
+        Ok(Arc::new(EncryptedFileCredential {
+            vault: self.vault.clone(),
+            passphrase: self.passphrase.clone(),
+            key: vault::entry_key(service, user),
+        }))
+    }
+}
+
+/// A single `(service, username)` entry in an [`EncryptedFileStore`].
+struct EncryptedFileCredential {
+    vault: Arc<JsonVault>,
+    passphrase: Arc<str>,
+    key: String,
+}
+
+impl CredentialApi for EncryptedFileCredential {
+    fn set_password(&self, password: &str) -> Result<(), StoreError> {
+        self.set_secret(password.as_bytes())
+    }
+
+    fn get_password(&self) -> Result<String, StoreError> {
+        let bytes = self.get_secret()?;
+        String::from_utf8(bytes)
+            .map_err(|_| to_store_error(crate::Error::PlatformError("stored secret is not valid UTF-8".into())))
+    }
+
+    fn set_secret(&self, secret: &[u8]) -> Result<(), StoreError> {
+        let sealed = vault::seal(secret, self.passphrase.as_bytes()).map_err(to_store_error)?;
+        self.vault.set(&self.key, sealed).map_err(to_store_error)
+    }
+
+    fn get_secret(&self) -> Result<Vec<u8>, StoreError> {
+        let sealed = self.vault.get(&self.key).map_err(to_store_error)?;
+        vault::open(&sealed, self.passphrase.as_bytes()).map_err(to_store_error)
+    }
+
+    fn delete_credential(&self) -> Result<(), StoreError> {
+        self.vault.remove(&self.key).map_err(to_store_error)
+    }
+
+    fn exists(&self) -> Result<bool, StoreError> {
+        self.vault.contains(&self.key).map_err(to_store_error)
+    }
+}
+
+fn to_store_error(e: crate::Error) -> StoreError {
+    match e {
+        crate::Error::NotFound => StoreError::NoEntry,
+        other => StoreError::PlatformFailure(Box::new(other)),
+    }
+}
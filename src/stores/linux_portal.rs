@@ -0,0 +1,131 @@
+//! A `keyring_core` store for sandboxed Linux distribution formats
+//! (Flatpak, Snap) where direct `org.freedesktop.Secret` access is
+//! unavailable and the app must go through `org.freedesktop.portal.Secret`
+//! instead — the same distinction `oo7` draws for desktop portals.
+//!
+//! Rather than asking the portal for a master secret and re-encrypting a
+//! second on-disk vault with it (duplicating storage `oo7` already owns),
+//! entries are stored directly as `oo7` portal-file-backend items, keyed by
+//! `service`/`username` attributes. `oo7` handles sealing them under the
+//! portal secret itself.
+
+use std::sync::Arc;
+
+use keyring_core::{CredentialApi, Error as StoreError, Store};
+
+/// A `keyring_core::Store` backed directly by `oo7`'s portal file backend,
+/// for use inside a Flatpak/Snap sandbox.
+pub struct PortalStore {
+    keyring: Arc<oo7::blocking::Keyring>,
+}
+
+impl PortalStore {
+    /// Opens the `org.freedesktop.portal.Secret`-backed keyring.
+    pub fn new() -> crate::Result<Self> {
+        let keyring = oo7::blocking::Keyring::new()
+            .map_err(|e| crate::Error::PlatformError(format!("secret portal request failed: {e}")))?;
+        Ok(Self {
+            keyring: Arc::new(keyring),
+        })
+    }
+}
+
+impl Store for PortalStore {
+    fn id(&self) -> String {
+        "linux-secret-portal".to_string()
+    }
+
+    fn build(
+        &self,
+        _target: Option<&str>,
+        service: &str,
+        user: &str,
+    ) -> Result<Arc<dyn CredentialApi>, StoreError> {
+        Ok(Arc::new(PortalCredential {
+            keyring: self.keyring.clone(),
+            service: service.to_string(),
+            user: user.to_string(),
+        }))
+    }
+}
+
+/// A single `(service, username)` entry in a [`PortalStore`].
+struct PortalCredential {
+    keyring: Arc<oo7::blocking::Keyring>,
+    service: String,
+    user: String,
+}
+
+impl PortalCredential {
+    fn attributes(&self) -> std::collections::HashMap<&str, &str> {
+        std::collections::HashMap::from([
+            ("service", self.service.as_str()),
+            ("username", self.user.as_str()),
+        ])
+    }
+
+    fn find_item(&self) -> Result<oo7::blocking::Item, StoreError> {
+        let items = self
+            .keyring
+            .search_items(&self.attributes())
+            .map_err(to_portal_error)?;
+        items.into_iter().next().ok_or(StoreError::NoEntry)
+    }
+}
+
+impl CredentialApi for PortalCredential {
+    fn set_password(&self, password: &str) -> Result<(), StoreError> {
+        self.set_secret(password.as_bytes())
+    }
+
+    fn get_password(&self) -> Result<String, StoreError> {
+        let bytes = self.get_secret()?;
+        String::from_utf8(bytes)
+            .map_err(|_| to_store_error(crate::Error::PlatformError("stored secret is not valid UTF-8".into())))
+    }
+
+    fn set_secret(&self, secret: &[u8]) -> Result<(), StoreError> {
+        self.keyring
+            .create_item(
+                &format!("{}:{}", self.service, self.user),
+                &self.attributes(),
+                secret,
+                true,
+            )
+            .map_err(to_portal_error)
+    }
+
+    fn get_secret(&self) -> Result<Vec<u8>, StoreError> {
+        self.find_item()?.secret().map(|s| s.to_vec()).map_err(to_portal_error)
+    }
+
+    fn delete_credential(&self) -> Result<(), StoreError> {
+        self.find_item()?.delete().map_err(to_portal_error)
+    }
+
+    fn exists(&self) -> Result<bool, StoreError> {
+        match self.find_item() {
+            Ok(_) => Ok(true),
+            Err(StoreError::NoEntry) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn to_store_error(e: crate::Error) -> StoreError {
+    match e {
+        crate::Error::NotFound => StoreError::NoEntry,
+        other => StoreError::PlatformFailure(Box::new(other)),
+    }
+}
+
+fn to_portal_error(e: oo7::Error) -> StoreError {
+    StoreError::PlatformFailure(Box::new(crate::Error::PlatformError(e.to_string())))
+}
+
+/// Whether the process is running inside a Flatpak or other container
+/// sandbox, per the markers `xdg-desktop-portal` consumers already check:
+/// `/.flatpak-info` (Flatpak) or a `container` env var (Snap, Docker, Podman).
+pub fn is_sandboxed() -> bool {
+    std::path::Path::new("/.flatpak-info").exists() || std::env::var_os("container").is_some()
+}
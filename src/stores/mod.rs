@@ -0,0 +1,4 @@
+pub mod encrypted_file;
+#[cfg(target_os = "linux")]
+pub mod linux_portal;
+mod vault;
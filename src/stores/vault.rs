@@ -0,0 +1,135 @@
+//! Scaffolding for the file-backed [`super::encrypted_file`] store: it
+//! persists a `(service, username) -> sealed value` map to a JSON file and
+//! seals every value with [`crate::crypto`] under a passphrase only the
+//! store holds. [`super::linux_portal`] does not use it; `oo7` owns that
+//! store's storage.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use scrypt::password_hash::rand_core::{OsRng, RngCore};
+
+pub(crate) fn entry_key(service: &str, username: &str) -> String {
+    format!("{service}:{username}")
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct VaultContents(HashMap<String, String>);
+
+/// A JSON-file-backed map of sealed values, written atomically.
+pub(crate) struct JsonVault {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl JsonVault {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn read_all(&self) -> crate::Result<VaultContents> {
+        match fs::read(&self.path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| crate::Error::PlatformError(format!("corrupt keyring vault: {e}"))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(VaultContents::default()),
+            Err(e) => Err(crate::Error::PlatformError(e.to_string())),
+        }
+    }
+
+    /// Writes to a sibling temp file and renames over `path` so readers
+    /// never observe a partially-written file (atomic on the same filesystem).
+    fn write_all(&self, contents: &VaultContents) -> crate::Result<()> {
+        let serialized = serde_json::to_vec_pretty(contents)
+            .map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, serialized).map_err(|e| crate::Error::PlatformError(e.to_string()))?;
+        fs::rename(&tmp_path, &self.path).map_err(|e| crate::Error::PlatformError(e.to_string()))
+    }
+
+    pub(crate) fn get(&self, key: &str) -> crate::Result<String> {
+        let _guard = self.lock.lock().expect("keyring vault lock poisoned");
+        // `read_all` returns an owned map, so removing from it just takes
+        // ownership of the one entry we want without touching the file.
+        self.read_all()?.0.remove(key).ok_or(crate::Error::NotFound)
+    }
+
+    pub(crate) fn set(&self, key: &str, value: String) -> crate::Result<()> {
+        let _guard = self.lock.lock().expect("keyring vault lock poisoned");
+        let mut contents = self.read_all()?;
+        contents.0.insert(key.to_string(), value);
+        self.write_all(&contents)
+    }
+
+    pub(crate) fn remove(&self, key: &str) -> crate::Result<()> {
+        let _guard = self.lock.lock().expect("keyring vault lock poisoned");
+        let mut contents = self.read_all()?;
+        if contents.0.remove(key).is_none() {
+            return Err(crate::Error::NotFound);
+        }
+        self.write_all(&contents)
+    }
+
+    pub(crate) fn contains(&self, key: &str) -> crate::Result<bool> {
+        let _guard = self.lock.lock().expect("keyring vault lock poisoned");
+        Ok(self.read_all()?.0.contains_key(key))
+    }
+}
+
+/// Seals `plaintext` under `key_material`, generating a fresh salt/nonce.
+pub(crate) fn seal(plaintext: &[u8], key_material: &[u8]) -> crate::Result<String> {
+    let mut salt = [0u8; 16];
+    let mut nonce = [0u8; 24];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+    crate::crypto::seal(plaintext, key_material, &salt, &nonce)
+}
+
+/// Opens a blob produced by [`seal`].
+pub(crate) fn open(sealed: &str, key_material: &[u8]) -> crate::Result<Vec<u8>> {
+    crate::crypto::open(sealed, key_material)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_vault(name: &str) -> JsonVault {
+        let path = std::env::temp_dir().join(format!("tauri-plugin-keyring-vault-test-{name}.json"));
+        let _ = fs::remove_file(&path);
+        JsonVault::new(path)
+    }
+
+    #[test]
+    fn set_then_get_round_trips() {
+        let vault = temp_vault("set-get");
+        vault.set("svc:alice", "sealed-value".into()).unwrap();
+        assert_eq!(vault.get("svc:alice").unwrap(), "sealed-value");
+    }
+
+    #[test]
+    fn get_missing_key_is_not_found() {
+        let vault = temp_vault("missing");
+        assert!(matches!(vault.get("nope"), Err(crate::Error::NotFound)));
+    }
+
+    #[test]
+    fn contains_reflects_set_and_remove() {
+        let vault = temp_vault("contains");
+        assert!(!vault.contains("svc:bob").unwrap());
+        vault.set("svc:bob", "sealed-value".into()).unwrap();
+        assert!(vault.contains("svc:bob").unwrap());
+        vault.remove("svc:bob").unwrap();
+        assert!(!vault.contains("svc:bob").unwrap());
+    }
+
+    #[test]
+    fn remove_missing_key_is_not_found() {
+        let vault = temp_vault("remove-missing");
+        assert!(matches!(vault.remove("nope"), Err(crate::Error::NotFound)));
+    }
+}
@@ -0,0 +1,60 @@
+//! Handle type for keyring operations run on a background thread.
+//!
+//! Some stores (notably the Linux D-Bus Secret Service backend) are
+//! inherently asynchronous and can block for hundreds of milliseconds while
+//! unlocking the collection or prompting the user. The `*_async` methods on
+//! `Keyring<R>` offload the blocking store call to `tauri::async_runtime`'s
+//! blocking pool and hand back a [`KeyringTask`] so a caller that can't just
+//! `.await` the result (e.g. a polling loop driving UI state) can check
+//! whether it's ready without blocking the main thread.
+//!
+//! This is a host-side (Rust) handle, not an IPC type — the frontend
+//! observes the same "waiting" state for free by awaiting the `Promise`
+//! from the `#[tauri::command]` wrappers in [`crate::commands`], which
+//! drive these tasks from the async runtime rather than polling them.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tauri::async_runtime::JoinHandle;
+
+/// A handle to an in-flight keyring operation spawned via `spawn_blocking`.
+///
+/// `is_finished` lets a caller poll for readiness (`Waiting` vs. done)
+/// without blocking the calling thread; `join` awaits the result.
+pub struct KeyringTask<T> {
+    ready: Arc<AtomicBool>,
+    handle: JoinHandle<crate::Result<T>>,
+}
+
+impl<T: Send + 'static> KeyringTask<T> {
+    pub(crate) fn spawn<F>(f: F) -> Self
+    where
+        F: FnOnce() -> crate::Result<T> + Send + 'static,
+    {
+        let ready = Arc::new(AtomicBool::new(false));
+        let ready_in_task = ready.clone();
+        let handle = tauri::async_runtime::spawn_blocking(move || {
+            let result = f();
+            ready_in_task.store(true, Ordering::Release);
+            result
+        });
+        Self { ready, handle }
+    }
+
+    /// Whether the underlying store call has finished. A caller can poll
+    /// this from a timer to know when it's safe to call [`Self::join`]
+    /// without blocking. Backed by an `AtomicBool` set just before the
+    /// spawned closure returns, since `JoinHandle` itself exposes no such
+    /// check.
+    pub fn is_finished(&self) -> bool {
+        self.ready.load(Ordering::Acquire)
+    }
+
+    /// Awaits the operation to completion.
+    pub async fn join(self) -> crate::Result<T> {
+        self.handle
+            .await
+            .map_err(|e| crate::Error::PlatformError(format!("keyring task panicked: {e}")))?
+    }
+}